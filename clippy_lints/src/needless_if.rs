@@ -1,12 +1,17 @@
-use clippy_utils::{diagnostics::span_lint_and_sugg, is_from_proc_macro, source::snippet_with_applicability};
+use clippy_utils::{
+    diagnostics::{span_lint_and_help, span_lint_and_sugg},
+    is_from_proc_macro,
+    source::{snippet_opt, snippet_with_applicability},
+};
 use rustc_errors::Applicability;
 use rustc_hir::{
     intravisit::{walk_expr, Visitor},
-    Expr, ExprKind, Node,
+    BinOpKind, Expr, ExprKind, Node, UnOp,
 };
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -19,67 +24,266 @@ declare_clippy_lint! {
     /// This will usually only suggest to remove the `if` statement, not the condition. Other lints
     /// such as `no_effect` will take care of removing the condition if it's unnecessary.
     ///
+    /// Blocks that hold only a comment or a `#[cfg(..)]`-gated statement are left alone, since
+    /// they're often used to document why a branch is intentionally empty.
+    ///
+    /// When the empty branch has a plain `else { .. }`, the condition is inverted and the `else`
+    /// branch is kept instead, since the `else` branch can't simply be removed. An empty branch
+    /// followed by an `else if` is not linted here; `empty_if_chain_branch` covers it.
+    ///
+    /// A lone `if let` with an empty body and no `else` is still flagged, but the scrutinee is
+    /// kept behind a `let _ = ..;` when it can have side effects, since the match itself may be
+    /// load-bearing even if the matched arm is not.
+    ///
     /// ### Example
     /// ```rust,ignore
     /// if really_expensive_condition(&i) {}
     /// if really_expensive_condition_with_side_effects(&mut i) {}
+    /// if really_expensive_condition(&i) {} else { bar(); }
     /// ```
     /// Use instead:
     /// ```rust,ignore
     /// // <omitted>
     /// really_expensive_condition_with_side_effects(&mut i);
+    /// if !really_expensive_condition(&i) { bar(); }
     /// ```
     #[clippy::version = "1.72.0"]
     pub NEEDLESS_IF,
     complexity,
     "checks for empty if branches"
 }
-declare_lint_pass!(NeedlessIf => [NEEDLESS_IF]);
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for empty branches in an `if`/`else if`/`else` chain: an empty `else if` or final
+    /// `else` branch, or an empty leading `if` branch that is followed by an `else if`.
+    ///
+    /// ### Why is this bad?
+    /// Unlike a lone `if {}`, an empty branch here can't just be deleted: its siblings still
+    /// need to run for their own conditions. An empty branch in the middle or at the end of a
+    /// chain usually means a case was forgotten rather than intentionally left blank, which is
+    /// the kind of defect MISRA-C:2004 Rule 14.10 (every `if`/`else if` chain must end in an
+    /// `else`) is meant to catch.
+    ///
+    /// ### Known issues
+    /// This lint is restriction-only: an empty branch isn't always a mistake, so it's up to the
+    /// author to decide whether to fill it in, fold it into a neighboring condition, or leave it
+    /// as-is.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// if x == 0 {
+    ///     foo();
+    /// } else if x == 1 {
+    /// } else {
+    ///     bar();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// if x == 0 {
+    ///     foo();
+    /// } else if x != 1 {
+    ///     bar();
+    /// }
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub EMPTY_IF_CHAIN_BRANCH,
+    restriction,
+    "checks for empty branches in an if/else if chain"
+}
+
+declare_lint_pass!(NeedlessIf => [NEEDLESS_IF, EMPTY_IF_CHAIN_BRANCH]);
 
 impl LateLintPass<'_> for NeedlessIf {
     fn check_expr<'tcx>(&mut self, cx: &LateContext<'tcx>, expr: &Expr<'tcx>) {
-        if let ExprKind::If(if_expr, block, else_expr) = &expr.kind
-            && let ExprKind::Block(block, ..) = block.kind
-            && block.stmts.is_empty()
-            && block.expr.is_none()
-            && else_expr.is_none()
-            && !in_external_macro(cx.sess(), expr.span)
-        {
-            // Ignore `else if`
-            if let Some(parent_id) = cx.tcx.hir().opt_parent_id(expr.hir_id)
-                && let Some(Node::Expr(Expr {
-                    kind: ExprKind::If(_, _, Some(else_expr)),
-                    ..
-                })) = cx.tcx.hir().find(parent_id)
-                && else_expr.hir_id == expr.hir_id
+        let ExprKind::If(if_expr, then, else_expr) = &expr.kind else {
+            return;
+        };
+        let ExprKind::Block(then_block, ..) = then.kind else {
+            return;
+        };
+        if in_external_macro(cx.sess(), expr.span) || is_from_proc_macro(cx, expr) {
+            return;
+        }
+
+        let then_is_empty = then_block.stmts.is_empty() && then_block.expr.is_none();
+
+        if is_else_if_branch(cx, expr) {
+            // `NEEDLESS_IF` only ever looks at the head of an `if`/`else if` chain: a branch
+            // with siblings can't just be deleted the way a lone `if {}` can. Report it under
+            // the restriction lint instead.
+            if then_is_empty && !block_has_comment_or_cfg(cx, then_block.span) {
+                span_lint_and_help(
+                    cx,
+                    EMPTY_IF_CHAIN_BRANCH,
+                    then_block.span,
+                    "this `else if` branch is empty",
+                    None,
+                    "fill in the branch, or fold its condition into the branch above it",
+                );
+            }
+        } else if then_is_empty {
+            // The block only *looks* empty: it holds a comment (often used as documentation for
+            // why the branch is intentionally blank) or a `#[cfg(..)]`-gated statement that was
+            // stripped out for this configuration. Leave it alone rather than eating the comment.
+            if block_has_comment_or_cfg(cx, then_block.span) {
+                return;
+            }
+
+            if let ExprKind::Let(_, scrutinee, ..) = if_expr.kind
+                && else_expr.is_none()
             {
+                // A lone `if let` with an empty body still needs its scrutinee evaluated for any
+                // side effects it may have, so, as with a plain `if` condition, it's kept (here
+                // behind a `let _ = ..;`) when it can have side effects.
+                let mut app = Applicability::MachineApplicable;
+                let snippet = snippet_with_applicability(cx, scrutinee.span, "{ ... }", &mut app);
+
+                span_lint_and_sugg(
+                    cx,
+                    NEEDLESS_IF,
+                    expr.span,
+                    "this `if let` branch is empty",
+                    "you can remove it",
+                    if scrutinee.can_have_side_effects() {
+                        format!("let _ = {snippet};")
+                    } else {
+                        String::new()
+                    },
+                    app,
+                );
                 return;
             }
 
-            if is_any_if_let(if_expr) || is_from_proc_macro(cx, expr) {
+            if is_any_if_let(if_expr) {
+                // Chained `let` conditions (`if let A = x && let B = y { }`), or a single
+                // `if let` that has an `else`: no single rewrite is correct for either, so leave
+                // these alone.
                 return;
             }
 
-            let mut app = Applicability::MachineApplicable;
-            let snippet = snippet_with_applicability(cx, if_expr.span, "{ ... }", &mut app);
+            match else_expr {
+                None => {
+                    let mut app = Applicability::MachineApplicable;
+                    let snippet = snippet_with_applicability(cx, if_expr.span, "{ ... }", &mut app);
 
-            span_lint_and_sugg(
-                cx,
-                NEEDLESS_IF,
-                expr.span,
-                "this `if` branch is empty",
-                "you can remove it",
-                if if_expr.can_have_side_effects() {
-                    format!("{snippet};")
-                } else {
-                    String::new()
+                    span_lint_and_sugg(
+                        cx,
+                        NEEDLESS_IF,
+                        expr.span,
+                        "this `if` branch is empty",
+                        "you can remove it",
+                        if if_expr.can_have_side_effects() {
+                            format!("{snippet};")
+                        } else {
+                            String::new()
+                        },
+                        app,
+                    );
+                },
+                // An `else if`/`else ... if` tail is its own bare `If` expression, not a
+                // `Block`: its span has no surrounding braces (or `else`) to slot the inverted
+                // condition in front of, so merging it in naively produces `if !a if b { .. }`,
+                // which isn't valid Rust. Only a plain `else { .. }` can be merged this way.
+                Some(else_expr) if matches!(else_expr.kind, ExprKind::Block(..)) => {
+                    let mut app = Applicability::MachineApplicable;
+                    let negated_cond = negate_condition(cx, if_expr, &mut app);
+                    let else_snippet = snippet_with_applicability(cx, else_expr.span, "{ ... }", &mut app);
+
+                    span_lint_and_sugg(
+                        cx,
+                        NEEDLESS_IF,
+                        expr.span,
+                        "this `if` branch is empty",
+                        "you can invert the condition and merge with the `else` branch",
+                        format!("if {negated_cond} {else_snippet}"),
+                        app,
+                    );
                 },
-                app,
+                // The empty `then` branch is the head of its own `if`/`else if` chain, so it's
+                // just as much a forgotten-case gap as an empty branch further down the chain:
+                // it can't be deleted outright (that would also drop the `else if`/`else` that
+                // follows), and there's no `Block` to merge it into.
+                Some(_) => {
+                    span_lint_and_help(
+                        cx,
+                        EMPTY_IF_CHAIN_BRANCH,
+                        then_block.span,
+                        "this `if` branch is empty",
+                        None,
+                        "fill in the branch, or fold its condition into the branch below it",
+                    );
+                },
+            }
+            return;
+        }
+
+        // A trailing empty `else` is the final branch of its chain, so it's reported under the
+        // same restriction lint as an empty `else if`.
+        if let Some(else_expr) = else_expr
+            && let ExprKind::Block(else_block, ..) = else_expr.kind
+            && else_block.stmts.is_empty()
+            && else_block.expr.is_none()
+            && !block_has_comment_or_cfg(cx, else_block.span)
+        {
+            span_lint_and_help(
+                cx,
+                EMPTY_IF_CHAIN_BRANCH,
+                else_block.span,
+                "this `else` branch is empty",
+                None,
+                "fill in the branch, or remove the `else` entirely",
             );
         }
     }
 }
 
+/// Returns true if `expr` is the `else if` (or final `else`, when itself an `if`) branch of a
+/// parent `if` expression, i.e. it isn't the head of its `if`/`else if` chain.
+fn is_else_if_branch(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let Some(parent_id) = cx.tcx.hir().opt_parent_id(expr.hir_id)
+        && let Some(Node::Expr(Expr {
+            kind: ExprKind::If(_, _, Some(else_expr)),
+            ..
+        })) = cx.tcx.hir().find(parent_id)
+    {
+        else_expr.hir_id == expr.hir_id
+    } else {
+        false
+    }
+}
+
+/// Returns the snippet for the negation of `cond`, adding parentheses where needed to preserve
+/// precedence. Simplifies trivially-negatable conditions instead of wrapping them in `!(..)`:
+/// `!x` becomes `x`, and `a != b` becomes `a == b`.
+fn negate_condition(cx: &LateContext<'_>, cond: &Expr<'_>, app: &mut Applicability) -> String {
+    // A non-`let` `if` condition is wrapped in `DropTemps` when lowered to HIR.
+    let cond = if let ExprKind::DropTemps(inner) = cond.kind { inner } else { cond };
+    match &cond.kind {
+        ExprKind::Unary(UnOp::Not, inner) => snippet_with_applicability(cx, inner.span, "..", app).into_owned(),
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::Ne => {
+            let lhs = snippet_with_applicability(cx, lhs.span, "..", app);
+            let rhs = snippet_with_applicability(cx, rhs.span, "..", app);
+            format!("{lhs} == {rhs}")
+        },
+        ExprKind::Binary(..) | ExprKind::Cast(..) | ExprKind::Closure(..) => {
+            format!("!({})", snippet_with_applicability(cx, cond.span, "..", app))
+        },
+        _ => format!("!{}", snippet_with_applicability(cx, cond.span, "..", app)),
+    }
+}
+
+/// Returns true if the snippet at `span` contains a line comment, a block comment, or the start
+/// of a `#[cfg(..)]` attribute, any of which indicate the block was left empty on purpose.
+fn block_has_comment_or_cfg(cx: &LateContext<'_>, span: Span) -> bool {
+    let Some(snippet) = snippet_opt(cx, span) else {
+        // Can't be sure there's nothing meaningful in there, so don't lint.
+        return true;
+    };
+    snippet.contains("//") || snippet.contains("/*") || snippet.contains("#[cfg")
+}
+
 /// Returns true if any `Expr` contained within this `Expr` is a `Let`, else false.
 ///
 /// Really wish `Expr` had a `walk` method...
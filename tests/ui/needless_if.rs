@@ -0,0 +1,130 @@
+#![feature(let_chains)]
+#![warn(clippy::needless_if, clippy::empty_if_chain_branch)]
+#![allow(
+    clippy::if_same_then_else,
+    clippy::ifs_same_cond,
+    clippy::needless_return,
+    clippy::nonminimal_bool,
+    unused
+)]
+
+fn main() {
+    fn f() -> bool {
+        true
+    }
+
+    if f() {} //~ ERROR: this `if` branch is empty
+
+    if f() {
+        // real work, not empty
+        real_work();
+    }
+
+    // A comment is treated as deliberate documentation for the empty branch, so this is left
+    // alone rather than having its contents eaten by the suggestion.
+    if f() {
+        // nothing to do here
+    }
+
+    // Likewise for a block that only holds a `#[cfg(..)]`-gated statement that happens to vanish
+    // in this configuration.
+    if f() {
+        #[cfg(any())]
+        do_something();
+    }
+
+    // An empty `then` branch with a non-empty `else` inverts the condition and merges the
+    // `else` branch in, instead of being left unhandled.
+    if f() {
+    } else {
+        real_work();
+    }
+    //~^^^^ ERROR: this `if` branch is empty
+
+    // A `!` on the condition is unwrapped rather than double-negated.
+    if !f() {
+    } else {
+        real_work();
+    }
+    //~^^^^ ERROR: this `if` branch is empty
+
+    // `!=` becomes `==` rather than `!(.. != ..)`.
+    if 1 != 2 {
+    } else {
+        real_work();
+    }
+    //~^^^^ ERROR: this `if` branch is empty
+
+    // An arbitrary condition gets parenthesized so negation doesn't change its meaning.
+    if f() && f() {
+    } else {
+        real_work();
+    }
+    //~^^^^ ERROR: this `if` branch is empty
+
+    // An `else if` tail has no block for the inverted condition to merge into, so `NEEDLESS_IF`
+    // leaves it alone. The empty head of the chain is still a forgotten-case gap though, so
+    // `empty_if_chain_branch` picks it up instead.
+    if f() {
+    } else if f() {
+        real_work();
+    }
+    //~^^^^ ERROR: this `if` branch is empty
+
+    // An empty branch in the middle of a 3-branch chain can't just be deleted, since the
+    // branches around it still need to run for their own conditions.
+    if f() {
+        real_work();
+    } else if f() {
+    } else {
+        real_work();
+    }
+    //~^^^^ ERROR: this `else if` branch is empty
+
+    // An empty trailing `else` is the final branch of the chain, so it's reported the same way.
+    if f() {
+        real_work();
+    } else if f() {
+        real_work();
+    } else {
+    }
+    //~^^ ERROR: this `else` branch is empty
+
+    // A plain 2-branch `if { .. } else {}` hits the same trailing-`else` case.
+    if f() {
+        real_work();
+    } else {
+    }
+    //~^^ ERROR: this `else` branch is empty
+
+    // A lone `if let` with an empty body and no `else` is still flagged: the scrutinee may have
+    // side effects that need to be kept around.
+    if let Some(_) = side_effecting() {}
+    //~^ ERROR: this `if let` branch is empty
+
+    // When the scrutinee plainly can't have side effects, the whole statement can go.
+    if let 1 = 1 {}
+    //~^ ERROR: this `if let` branch is empty
+
+    // An `if let` with an `else` is left alone: unlike a plain condition, there's no single
+    // rewrite that preserves the pattern match.
+    if let Some(_) = side_effecting() {
+    } else {
+        real_work();
+    }
+
+    // Chained `let` conditions are left alone for the same reason.
+    if let Some(_) = Some(1)
+        && let Some(_) = Some(2)
+    {
+    }
+}
+
+fn side_effecting() -> Option<i32> {
+    println!("side effect");
+    Some(1)
+}
+
+fn real_work() {}
+
+fn do_something() {}